@@ -17,7 +17,8 @@ const readWriteConnection = sqlite3(dbPath)
 readonlyConnection.defaultSafeIntegers()
 readWriteConnection.defaultSafeIntegers()
 
-const find_widget_regexp = (text: string, pattern: string, wholeWord: 0n | 1n, caseSensitive: 0n | 1n) => {
+const find_widget_regexp = (text: string | Buffer | bigint | number, pattern: string, wholeWord: 0n | 1n, caseSensitive: 0n | 1n) => {
+    text = text instanceof Buffer ? text.toString("hex") : String(text)  // BLOB, INTEGER, REAL
     try {
         return new RegExp(wholeWord ? `\\b(?:${pattern})\\b` : pattern, caseSensitive ? "" : "i").test(text) ? 1n : 0n
     } catch (err) {
@@ -8,6 +8,12 @@ import { BigintMath, createStore } from "./util"
 import deepEqual from "fast-deep-equal"
 import type { JSXInternal } from "preact/src/jsx"
 
+/** The expression the non-regex find modes compare a cell against. BLOBs are compared as lowercase hex, as shown in the grid. */
+const findWidgetCell = (column: string) => {
+    const ident = escapeSQLIdentifier(column)
+    return `CASE WHEN typeof(${ident}) = 'blob' THEN lower(hex(${ident})) ELSE IFNULL(${ident}, 'NULL') END`
+}
+
 /** Build the WHERE clause from the state of the find widget */
 const buildFindWidgetQuery = (tableInfo: remote.TableInfo) => {
     const { findWidget, isFindWidgetVisible } = useTableStore.getState()
@@ -20,15 +26,15 @@ const buildFindWidgetQuery = (tableInfo: remote.TableInfo) => {
             } else {
                 if (findWidget.wholeWord) {
                     if (findWidget.caseSensitive) {
-                        findWidgetQuery = tableInfo.map(({ name: column }) => `IFNULL(${escapeSQLIdentifier(column)}, 'NULL') = ?`).join(" OR ")
+                        findWidgetQuery = tableInfo.map(({ name: column }) => `${findWidgetCell(column)} = ?`).join(" OR ")
                     } else {
-                        findWidgetQuery = tableInfo.map(({ name: column }) => `UPPER(IFNULL(${escapeSQLIdentifier(column)}, 'NULL')) = UPPER(?)`).join(" OR ")
+                        findWidgetQuery = tableInfo.map(({ name: column }) => `UPPER(${findWidgetCell(column)}) = UPPER(?)`).join(" OR ")
                     }
                 } else {
                     if (findWidget.caseSensitive) {
-                        findWidgetQuery = tableInfo.map(({ name: column }) => `INSTR(IFNULL(${escapeSQLIdentifier(column)}, 'NULL'), ?) > 0`).join(" OR ")
+                        findWidgetQuery = tableInfo.map(({ name: column }) => `INSTR(${findWidgetCell(column)}, ?) > 0`).join(" OR ")
                     } else {
-                        findWidgetQuery = tableInfo.map(({ name: column }) => `INSTR(UPPER(IFNULL(${escapeSQLIdentifier(column)}, 'NULL')), UPPER(?)) > 0`).join(" OR ")
+                        findWidgetQuery = tableInfo.map(({ name: column }) => `INSTR(UPPER(${findWidgetCell(column)}), UPPER(?)) > 0`).join(" OR ")
                     }
                 }
             }
@@ -126,3 +126,59 @@ test.describe("UPDATE", () => {
         /* Checks if the cell (0, 0) is not selected. */await expect(page.getByTestId(`cell 0, 0`).getByTestId("inplaceInput")).not.toBeVisible()
     })
 })
+
+/** Inputs a value into the find widget and applies it. */
+const find = async ({ page }: { page: Page }, value: string) => {
+    /* Input the value. */await page.locator('#findWidget').fill(value)
+    /* Apply the search. */await page.locator('#findWidget').press('Enter')
+}
+
+/** Searches for a value that matches nothing so that the next search has to change the table. */
+const resetFind = async ({ page }: { page: Page }) => {
+    await find({ page }, 'no-such-value')
+    /* Check if all records are filtered out. */await expect(page.getByTestId('row number 1')).not.toBeVisible()
+}
+
+/** Checks if the BLOB is the only record found. */
+const expectOnlyBlobFound = async ({ page }: { page: Page }) => {
+    /* Check if the BLOB is in the first row. */await expect(page.getByTestId('cell 0, 3')).toHaveText("x'89504e470d0a1a0a'")
+    /* Check if the other records are filtered out. */await expect(page.getByTestId('row number 2')).not.toBeVisible()
+}
+
+test("Find > BLOB by hex pattern", async ({ page }) => {
+    await page.goto('http://localhost:5173/')
+
+    const query = `INSERT INTO "test-table" ("blob-column") VALUES (x'89504e470d0a1a0a')`
+    /* Open the custom query editor. */await page.getByTestId('custom-query-button').click()
+    /* Input a query that inserts a BLOB. */await page.getByPlaceholder('CREATE TABLE table1(column1 INTEGER)').fill(query)
+    /* Check if the commit button's title has been updated. */await expect(page.getByTitle(query)).toBeVisible()
+    /* Click the commit button. */await page.getByTestId('commit').click()
+
+    /* Open the find widget. */await page.getByTestId('find-button').click()
+
+    // Without regular expressions
+    /* Search for a lowercase hex pattern. */await find({ page }, '89504e47')
+    await expectOnlyBlobFound({ page })
+    await resetFind({ page })
+    /* Search for an uppercase hex pattern. */await find({ page }, '89504E47')
+    await expectOnlyBlobFound({ page })
+    await resetFind({ page })
+    /* Enable whole-word matching. */await page.locator('#findWidget').press('Alt+KeyW')
+    /* Search for the whole BLOB. */await find({ page }, '89504e470d0a1a0a')
+    await expectOnlyBlobFound({ page })
+    /* Search for a part of the BLOB. */await find({ page }, '89504e47')
+    /* Check if the part does not match as a whole word. */await expect(page.getByTestId('row number 1')).not.toBeVisible()
+    /* Disable whole-word matching. */await page.locator('#findWidget').press('Alt+KeyW')
+    await resetFind({ page })
+
+    // With regular expressions
+    /* Enable regular expressions. */await page.locator('#findWidget').press('Alt+KeyR')
+    /* Search for a lowercase hex pattern. */await find({ page }, '89504e47')
+    await expectOnlyBlobFound({ page })
+    await resetFind({ page })
+    /* Search for an uppercase hex pattern. */await find({ page }, '89504E47')
+    await expectOnlyBlobFound({ page })
+
+    /* Enable case sensitivity. */await page.locator('#findWidget').press('Alt+KeyC')
+    /* Check if the uppercase pattern no longer matches. */await expect(page.getByTestId('row number 1')).not.toBeVisible()
+})
@@ -5,13 +5,15 @@ import sqlite3
 import sys
 import traceback
 import urllib.parse
+from typing import Union
 
 from umsgpack import pack, unpack
 
 
-def find_widget_regexp(text: str, pattern: str, whole_word: int, case_sensitive: int):
+def find_widget_regexp(text: Union[str, bytes, int, float], pattern: str, whole_word: int, case_sensitive: int):
+    text = text.hex() if isinstance(text, bytes) else str(text)  # BLOB, INTEGER, REAL
     try:
-        return 0 if re.search(pattern, "\\b(?:{pattern})\\b" if whole_word else text, 0 if case_sensitive else re.RegexFlag.I) is None else 1
+        return 0 if re.search(f"\\b(?:{pattern})\\b" if whole_word else pattern, text, 0 if case_sensitive else re.RegexFlag.I) is None else 1
     except re.error:  # Invalid regular expressions
         return 0
 